@@ -0,0 +1,95 @@
+//! A value that is computed once, on first access, from a stored closure.
+//!
+//! Built on top of [`LateInit`](crate::LateInit): instead of requiring an explicit `init` call
+//! site, the value is produced lazily by `F` the first time it is dereferenced.
+
+use core::{
+    cell::UnsafeCell,
+    ops::Deref,
+};
+
+use crate::LateInit;
+
+/// Lazily-initialized value, computed from `F` on first access.
+pub struct Lazy<T, F = fn() -> T> {
+    value: LateInit<T>,
+    init: UnsafeCell<Option<F>>,
+}
+
+impl <T, F> Lazy<T, F> {
+    /// Create a new Lazy from an initializer closure. The closure is not called until the
+    /// first access.
+    pub const fn new(f: F) -> Self {
+        Lazy {
+            value: LateInit::new(),
+            init: UnsafeCell::new(Some(f)),
+        }
+    }
+}
+
+impl <T, F: FnOnce() -> T> Lazy<T, F> {
+    /// Force evaluation of the contained value, running the initializer on first access.
+    pub fn force(&self) -> &T {
+        if self.value.option().is_none() {
+            if let Some(f) = unsafe { (*self.init.get()).take() } {
+                // Safety: `Lazy` is `!Sync` (via the `UnsafeCell` above), so `&self` here can't
+                // be shared with another thread calling `force`/`get` concurrently. If another
+                // call to `force` already won the race (not possible without `Sync`, but kept
+                // for symmetry with `LateInit::set`), just drop the result.
+                let _ = unsafe { self.value.set(f()) };
+            }
+        }
+
+        self.value.data()
+    }
+
+    /// Get the contained value without forcing it, i.e. returns `None` if not yet accessed.
+    pub fn get(&self) -> Option<&T> {
+        // Safety: `Lazy` is `!Sync`, so there's no concurrent `init`/`set` to race with.
+        unsafe { self.value.get() }
+    }
+}
+
+impl <T, F: FnOnce() -> T> Deref for Lazy<T, F> {
+    type Target = T;
+
+    /// Forces evaluation, then derefs to the contained value.
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.force()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn forces_once() {
+        let calls = core::cell::Cell::new(0);
+        let lazy = Lazy::new(|| {
+            calls.set(calls.get() + 1);
+            4
+        });
+
+        assert_eq!(lazy.get(), None);
+        assert_eq!(*lazy.force(), 4);
+        assert_eq!(*lazy.force(), 4);
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn deref_forces() {
+        let lazy = Lazy::new(|| 4);
+        assert_eq!(*lazy, 4);
+    }
+
+    #[test]
+    fn get_before_and_after_force() {
+        let lazy = Lazy::new(|| 4);
+        assert_eq!(lazy.get(), None);
+
+        lazy.force();
+        assert_eq!(lazy.get(), Some(&4));
+    }
+}
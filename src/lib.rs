@@ -45,6 +45,12 @@ use core::{
     }
 };
 
+pub mod sync;
+pub mod lazy;
+
+pub use sync::SyncLateInit;
+pub use lazy::Lazy;
+
 /// The primary type for this crate. Initialize before use.
 // We use UnsafeCell because we need interior mutability, and we're not using Cell because we don't
 //  want any runtime cost. There isn't any principled reason this is UnsafeCell<Option> rather than
@@ -69,13 +75,77 @@ impl <T> LateInit<T> {
         *self.0.get() = Some(value);
     }
 
+    /// Assign a value, returning the value back unconsumed if already initialized, rather than
+    /// panicking.
+    ///
+    /// # Safety
+    ///
+    /// `LateInit` is unconditionally `Sync` but does not synchronize its accesses, so this
+    /// carries the same caller contract as `init`: the caller must ensure no other thread is
+    /// concurrently calling `init`/`set`/`get`/`is_init`/`take` on the same instance. Use
+    /// `SyncLateInit` if that can't be guaranteed.
+    pub unsafe fn set(&self, value: T) -> Result<(), T> {
+        if self.option().is_some() {
+            return Err(value);
+        }
+
+        *self.0.get() = Some(value);
+        Ok(())
+    }
+
+    /// Get the contained value, or `None` if not yet initialized.
+    ///
+    /// # Safety
+    ///
+    /// Carries the same caller contract as [`set`](Self::set): callers must synchronize against
+    /// concurrent `init`/`set`.
+    pub unsafe fn get(&self) -> Option<&T> {
+        self.option().as_ref()
+    }
+
+    /// Alias for [`get`](Self::get).
+    ///
+    /// # Safety
+    ///
+    /// Carries the same caller contract as [`get`](Self::get).
+    pub unsafe fn try_get(&self) -> Option<&T> {
+        self.get()
+    }
+
+    /// Whether the value has been initialized.
+    ///
+    /// # Safety
+    ///
+    /// Carries the same caller contract as [`set`](Self::set): callers must synchronize against
+    /// concurrent `init`/`set`.
+    pub unsafe fn is_init(&self) -> bool {
+        self.option().is_some()
+    }
+
+    /// Get mutable access to the contained value, or `None` if not yet initialized. Safe because
+    /// `&mut self` proves exclusive access.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        self.0.get_mut().as_mut()
+    }
+
+    /// Reset to uninitialized, returning the contained value if there was one. A subsequent
+    /// `init`/`set` call may initialize it again.
+    pub fn take(&mut self) -> Option<T> {
+        self.0.get_mut().take()
+    }
+
+    /// Consume the `LateInit`, returning the contained value if there was one.
+    pub fn into_inner(self) -> Option<T> {
+        self.0.into_inner()
+    }
+
     #[inline(always)]
-    fn option(&self) -> &Option<T> {
+    pub(crate) fn option(&self) -> &Option<T> {
         unsafe { &*self.0.get() }
     }
 
     #[inline(always)]
-    fn data(&self) -> &T {
+    pub(crate) fn data(&self) -> &T {
         #[cfg(not(feature = "unchecked"))] {
             debug_assert!(self.option().is_some(), "LateInit used without initialization");
         }
@@ -94,7 +164,6 @@ impl <T: Clone> LateInit<T> {
     /// support mutation, so `clone_from` is impossible.
     #[inline(always)]
     pub fn clone(&self) -> T {
-        self.assert_option();
         self.data().clone()
     }
 }
@@ -235,4 +304,74 @@ mod test {
         let li = LateInit::<usize>::new();
         let _ = li == 4;
     }
+
+    #[test]
+    fn set_ok_then_err() {
+        let li = LateInit::<usize>::new();
+
+        unsafe {
+            assert_eq!(li.set(4), Ok(()));
+            assert_eq!(li.set(5), Err(5));
+        }
+        assert_eq!(*li, 4);
+    }
+
+    #[test]
+    fn get_before_and_after_init() {
+        let li = LateInit::<usize>::new();
+        unsafe {
+            assert_eq!(li.get(), None);
+            assert_eq!(li.try_get(), None);
+
+            li.init(4);
+
+            assert_eq!(li.get(), Some(&4));
+            assert_eq!(li.try_get(), Some(&4));
+        }
+    }
+
+    #[test]
+    fn is_init() {
+        let li = LateInit::<usize>::new();
+        unsafe {
+            assert!(!li.is_init());
+
+            li.init(4);
+            assert!(li.is_init());
+        }
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut li = LateInit::<usize>::new();
+        assert_eq!(li.get_mut(), None);
+
+        unsafe { li.init(4); }
+        *li.get_mut().unwrap() = 5;
+
+        assert_eq!(*li, 5);
+    }
+
+    #[test]
+    fn take_resets_and_allows_reinit() {
+        let mut li = LateInit::<usize>::new();
+        assert_eq!(li.take(), None);
+
+        unsafe { li.init(4); }
+        assert_eq!(li.take(), Some(4));
+        assert!(unsafe { !li.is_init() });
+
+        unsafe { li.init(5); }
+        assert_eq!(*li, 5);
+    }
+
+    #[test]
+    fn into_inner() {
+        let li = LateInit::<usize>::new();
+        assert_eq!(li.into_inner(), None);
+
+        let li = LateInit::<usize>::new();
+        unsafe { li.init(4); }
+        assert_eq!(li.into_inner(), Some(4));
+    }
 }
\ No newline at end of file
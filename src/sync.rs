@@ -0,0 +1,318 @@
+//! A thread-safe sibling of [`LateInit`](crate::LateInit).
+//!
+//! `SyncLateInit` keeps the same single-assignment, panic-on-double-init contract, but makes
+//! `init` safe to call from multiple threads racing to be the one that sets the value.
+//!
+//! With the `critical-section` feature enabled, the write path is instead guarded by a global
+//! critical section (via the `critical-section` crate) rather than a CAS, for bare-metal
+//! `no_std` targets without a portable atomics story.
+
+use core::{
+    ops::Deref,
+    cmp::{
+        PartialEq,
+        PartialOrd,
+        Ordering
+    },
+    cell::UnsafeCell,
+    convert::AsRef,
+    mem::MaybeUninit,
+    fmt::{
+        Display,
+        Debug,
+        Formatter,
+        Error as FmtError
+    },
+    sync::atomic::{
+        AtomicU8,
+        Ordering as AtomicOrdering
+    },
+};
+
+const INCOMPLETE: u8 = 0;
+#[cfg(not(feature = "critical-section"))]
+const RUNNING: u8 = 1;
+const COMPLETE: u8 = 2;
+const PANICKED: u8 = 3;
+
+/// Thread-safe version of [`LateInit`](crate::LateInit).
+///
+/// Uses an atomic state machine instead of a bare `UnsafeCell<Option<T>>` so that concurrent
+/// callers of `init` race safely: exactly one wins and writes the value, the rest panic instead
+/// of causing a data race.
+pub struct SyncLateInit<T> {
+    state: AtomicU8,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+unsafe impl <T> Sync for SyncLateInit<T> where T: Send + Sync {}
+unsafe impl <T> Send for SyncLateInit<T> where T: Send {}
+
+impl <T> SyncLateInit<T> {
+    /// Create a new SyncLateInit.
+    pub const fn new() -> Self {
+        SyncLateInit {
+            state: AtomicU8::new(INCOMPLETE),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Assign a value. Panics if called more than once, including when racing with another
+    /// thread that is in the middle of its own `init` call.
+    pub fn init(&self, value: T) {
+        if self.set(value).is_err() {
+            match self.state.load(AtomicOrdering::Acquire) {
+                PANICKED => panic!("SyncLateInit poisoned by a panic during init"),
+                _ => panic!("SyncLateInit.init called more than once"),
+            }
+        }
+    }
+
+    /// Assign a value, returning the value back unconsumed if already initialized (or another
+    /// thread is concurrently in the middle of initializing it), rather than panicking.
+    ///
+    /// With the `critical-section` feature enabled, the write is instead guarded by a global
+    /// critical section rather than a CAS on `state`, for targets without a portable
+    /// compare-and-swap (bare-metal single-core MCUs).
+    #[cfg(not(feature = "critical-section"))]
+    pub fn set(&self, value: T) -> Result<(), T> {
+        match self.state.compare_exchange(INCOMPLETE, RUNNING, AtomicOrdering::Acquire, AtomicOrdering::Acquire) {
+            Ok(_) => {
+                // Guards against the write panicking (e.g. inside a misbehaving `Drop` of the
+                // value being overwritten, which cannot happen here, but keeps the state machine
+                // honest if `write` is ever replaced by something fallible).
+                let guard = PanicGuard(&self.state);
+                unsafe { (*self.value.get()).as_mut_ptr().write(value); }
+                core::mem::forget(guard);
+
+                self.state.store(COMPLETE, AtomicOrdering::Release);
+                Ok(())
+            },
+            Err(_) => Err(value),
+        }
+    }
+
+    /// Assign a value, returning the value back unconsumed if already initialized, rather than
+    /// panicking. Masks interrupts for the duration of the write via `critical_section::with` so
+    /// no reentrant interrupt handler or other core can observe a torn state, without relying on
+    /// `compare_exchange` being available.
+    #[cfg(feature = "critical-section")]
+    pub fn set(&self, value: T) -> Result<(), T> {
+        critical_section::with(|_cs| {
+            if self.state.load(AtomicOrdering::Relaxed) != INCOMPLETE {
+                return Err(value);
+            }
+
+            unsafe { (*self.value.get()).as_mut_ptr().write(value); }
+            self.state.store(COMPLETE, AtomicOrdering::Release);
+            Ok(())
+        })
+    }
+
+    /// Get the contained value, or `None` if not yet initialized.
+    pub fn get(&self) -> Option<&T> {
+        match self.state.load(AtomicOrdering::Acquire) {
+            COMPLETE => Some(unsafe { &*(*self.value.get()).as_ptr() }),
+            _ => None,
+        }
+    }
+
+    /// Alias for [`get`](Self::get).
+    pub fn try_get(&self) -> Option<&T> {
+        self.get()
+    }
+
+    /// Whether the value has been initialized.
+    pub fn is_init(&self) -> bool {
+        self.state.load(AtomicOrdering::Acquire) == COMPLETE
+    }
+
+    #[inline(always)]
+    fn data(&self) -> &T {
+        match self.state.load(AtomicOrdering::Acquire) {
+            COMPLETE => unsafe { &*(*self.value.get()).as_ptr() },
+            PANICKED => panic!("SyncLateInit poisoned by a panic during init"),
+            _ => panic!("SyncLateInit used without initialization"),
+        }
+    }
+}
+
+/// Marks the state as `PANICKED` if dropped before being disarmed, i.e. if `init` unwinds
+/// partway through writing the value.
+#[cfg(not(feature = "critical-section"))]
+struct PanicGuard<'a>(&'a AtomicU8);
+
+#[cfg(not(feature = "critical-section"))]
+impl <'a> Drop for PanicGuard<'a> {
+    fn drop(&mut self) {
+        self.0.store(PANICKED, AtomicOrdering::Release);
+    }
+}
+
+impl <T: Clone> SyncLateInit<T> {
+    /// Clone contained value. Panics if called before initialization.
+    #[inline(always)]
+    pub fn clone(&self) -> T {
+        self.data().clone()
+    }
+}
+
+impl <T> Deref for SyncLateInit<T> {
+    type Target = T;
+
+    /// Deref to contained value. Panics if called before initialization.
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.data()
+    }
+}
+
+impl <T> AsRef<T> for SyncLateInit<T> {
+    /// Panics if called before initialization.
+    #[inline(always)]
+    fn as_ref(&self) -> &T {
+        self.data()
+    }
+}
+
+impl <T: PartialEq<W>, W> PartialEq<W> for SyncLateInit<T> {
+    #[inline(always)]
+    fn eq(&self, other: &W) -> bool {
+        self.data().eq(other)
+    }
+
+    #[inline(always)]
+    fn ne(&self, other: &W) -> bool {
+        self.data().ne(other)
+    }
+}
+
+impl <T: PartialOrd<W>, W> PartialOrd<W> for SyncLateInit<T> {
+    fn partial_cmp(&self, other: &W) -> Option<Ordering> {
+        self.data().partial_cmp(other)
+    }
+
+    fn lt(&self, other: &W) -> bool {
+        self.data().lt(other)
+    }
+
+    fn le(&self, other: &W) -> bool {
+        self.data().le(other)
+    }
+
+    fn gt(&self, other: &W) -> bool {
+        self.data().gt(other)
+    }
+
+    fn ge(&self, other: &W) -> bool {
+        self.data().ge(other)
+    }
+}
+
+impl <T: Debug> Debug for SyncLateInit<T> {
+    /// Delegates to `Debug` implementation on contained value. This is a checked access.
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match self.state.load(AtomicOrdering::Acquire) {
+            COMPLETE => unsafe { (*(*self.value.get()).as_ptr()).fmt(f) },
+            _ => write!(f, "<UNINITIALIZED>"),
+        }
+    }
+}
+
+impl <T: Display> Display for SyncLateInit<T> {
+    /// Delegates to `Display` implementation on contained value. This is a checked access.
+    fn fmt(&self, f: &mut Formatter) -> Result<(), FmtError> {
+        match self.state.load(AtomicOrdering::Acquire) {
+            COMPLETE => unsafe { (*(*self.value.get()).as_ptr()).fmt(f) },
+            _ => write!(f, "<UNINITIALIZED>"),
+        }
+    }
+}
+
+impl <T> Drop for SyncLateInit<T> {
+    fn drop(&mut self) {
+        if *self.state.get_mut() == COMPLETE {
+            unsafe { (*self.value.get()).as_mut_ptr().drop_in_place(); }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use core::convert::AsRef;
+    use core::ops::Deref;
+
+    #[test]
+    #[should_panic]
+    fn multiple_init_panics() {
+        let li = SyncLateInit::<usize>::new();
+        li.init(4);
+        li.init(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn as_ref_panics() {
+        let li = SyncLateInit::<usize>::new();
+        let _ = li.as_ref();
+    }
+
+    #[test]
+    #[should_panic]
+    fn deref_panics() {
+        let li = SyncLateInit::<usize>::new();
+        let _ = li.deref();
+    }
+
+    #[test]
+    fn compare() {
+        let li = SyncLateInit::<usize>::new();
+        li.init(4);
+
+        assert!(li > 3);
+        assert!(li < 5);
+        assert!(li >= 4);
+        assert!(li <= 4);
+    }
+
+    #[test]
+    fn eq() {
+        let li = SyncLateInit::<usize>::new();
+        li.init(4);
+
+        assert_eq!(li, 4);
+        assert_ne!(li, 5);
+    }
+
+    #[test]
+    fn set_ok_then_err() {
+        let li = SyncLateInit::<usize>::new();
+
+        assert_eq!(li.set(4), Ok(()));
+        assert_eq!(li.set(5), Err(5));
+        assert_eq!(*li, 4);
+    }
+
+    #[test]
+    fn get_before_and_after_init() {
+        let li = SyncLateInit::<usize>::new();
+        assert_eq!(li.get(), None);
+        assert_eq!(li.try_get(), None);
+
+        li.init(4);
+
+        assert_eq!(li.get(), Some(&4));
+        assert_eq!(li.try_get(), Some(&4));
+    }
+
+    #[test]
+    fn is_init() {
+        let li = SyncLateInit::<usize>::new();
+        assert!(!li.is_init());
+
+        li.init(4);
+        assert!(li.is_init());
+    }
+}